@@ -40,6 +40,30 @@ fn get_selector(meta_list: &[MetaAttr]) -> Option<String> {
     None
 }
 
+fn is_generic_errors(meta_list: &[MetaAttr]) -> bool {
+    meta_list.iter().any(|m| m.is_type(MetaAttrType::GenericErrors))
+}
+
+fn get_error_kind(meta_list: &[MetaAttr]) -> proc_macro2::TokenStream {
+    for meta in meta_list {
+        if meta.is_type(MetaAttrType::GenericErrors) {
+            if let Some(arg) = meta.arg() {
+                return arg;
+            }
+        }
+    }
+    quote!{ nom::error::ErrorKind::Switch }
+}
+
+fn get_tag_parser(meta_list: &[MetaAttr]) -> Option<proc_macro2::TokenStream> {
+    for meta in meta_list {
+        if meta.is_type(MetaAttrType::Tag) {
+            return meta.arg();
+        }
+    }
+    None
+}
+
 fn get_repr(attrs: &[syn::Attribute]) -> Option<String> {
     for attr in attrs {
         if let Ok(ref meta) = attr.parse_meta() {
@@ -75,15 +99,43 @@ fn get_repr(attrs: &[syn::Attribute]) -> Option<String> {
     None
 }
 
+fn is_other_variant(variant: &syn::Variant) -> bool {
+    match meta::parse_nom_attribute(&variant.attrs) {
+        Ok(meta_list) => meta_list.iter().any(|m| m.is_type(MetaAttrType::Other)),
+        Err(_) => false,
+    }
+}
+
+// handles negated literals too (`-1` parses as `Expr::Unary(Neg, Expr::Lit(1))`, not `Expr::Lit`)
+fn discriminant_value(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit{ lit: syn::Lit::Int(ref lit_int), .. }) => {
+            lit_int.base10_parse::<i128>().ok()
+        }
+        syn::Expr::Unary(syn::ExprUnary{ op: syn::UnOp::Neg(_), expr: inner, .. }) => {
+            discriminant_value(inner).map(|v| -v)
+        }
+        _ => None,
+    }
+}
+
 fn is_input_fieldless_enum(ast: &syn::DeriveInput) -> bool {
     match ast.data {
         syn::Data::Enum(ref data_enum) => {
             // eprintln!("{:?}", data_enum);
+            let mut other_seen = false;
             data_enum.variants.iter()
-                .fold(true,
-                      |acc, v| {
-                          if let syn::Fields::Unit = v.fields { acc } else { false }
-                      })
+                .all(|v| {
+                    match v.fields {
+                        syn::Fields::Unit => true,
+                        syn::Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 && is_other_variant(v) => {
+                            if other_seen { return false; }
+                            other_seen = true;
+                            true
+                        },
+                        _ => false,
+                    }
+                })
         },
         _ => false
     }
@@ -119,36 +171,108 @@ fn impl_nom_fieldless_enums(ast: &syn::DeriveInput, repr:String, meta_list: &[Me
         }
         _ => panic!("Cannot parse 'repr' content")
     };
-    let variant_names : Vec<_> =
-        match ast.data {
-            syn::Data::Enum(ref data_enum) => {
-                // eprintln!("{:?}", data_enum);
-                data_enum.variants.iter()
-                    .map(|v| {
-                        v.ident.to_string()
-                    })
-                    .collect()
-            },
-            _ => { panic!("expect enum"); }
-        };
+    // declaration-order (ident, discriminant, is_other): 'Other' still consumes a positional slot
+    let mut ordered_variants : Vec<(syn::Ident, Option<syn::Expr>, bool)> = Vec::new();
+    let mut other_variant : Option<syn::Ident> = None;
+    match ast.data {
+        syn::Data::Enum(ref data_enum) => {
+            // eprintln!("{:?}", data_enum);
+            for v in data_enum.variants.iter() {
+                let is_other = is_other_variant(v);
+                if is_other {
+                    other_variant = Some(v.ident.clone());
+                }
+                let discriminant = v.discriminant.as_ref().map(|(_, expr)| expr.clone());
+                ordered_variants.push((v.ident.clone(), discriminant, is_other));
+            }
+        },
+        _ => { panic!("expect enum"); }
+    };
     let generics = &ast.generics;
     let name = &ast.ident;
-    let ty = syn::Ident::new(&repr, Span::call_site());
+    // u24/i24 are not real Rust types: the selector is parsed into (and compared as) u32/i32
+    let selector_ty = match repr.as_ref() {
+        "u24" => "u32",
+        "i24" => "i32",
+        other => other,
+    };
+    let selector_ty = syn::Ident::new(selector_ty, Span::call_site());
+    let mut next_discriminant : i128 = 0;
+    // a data-carrying 'Other' variant makes the enum non-fieldless to rustc, so
+    // `#name::#id as #ty` is rejected (E0605); compare against the explicit discriminant
+    // when one is given, and against the positional value otherwise
+    let variant_values : Vec<_> =
+        ordered_variants.iter()
+            .filter_map(|(id, discriminant, is_other)| {
+                let value = match discriminant {
+                    Some(expr) => {
+                        next_discriminant = discriminant_value(expr)
+                            .unwrap_or_else(|| panic!("Nom-derive: the discriminant of variant {} must be a (possibly negated) integer literal", id));
+                        quote!{ (#expr) as #selector_ty }
+                    }
+                    None => {
+                        let v = next_discriminant;
+                        quote!{ #v as #selector_ty }
+                    }
+                };
+                next_discriminant += 1;
+                if *is_other { None } else { Some((id, value)) }
+            })
+            .collect();
     let variants_code : Vec<_> =
-        variant_names.iter()
-            .map(|variant_name| {
-                let id = syn::Ident::new(variant_name, Span::call_site());
-                quote!{ if selector == #name::#id as #ty { return Ok((#input_name, #name::#id)); } }
+        variant_values.iter()
+            .map(|(id, value)| {
+                quote!{ if selector == #value { return Ok((#input_name, #name::#id)); } }
             })
             .collect();
-    let tokens = quote!{
-        impl#generics #name#generics {
-            fn parse(#orig_input_name: &[u8]) -> nom::IResult<&[u8],#name> {
-                let #input_name = #orig_input_name;
-                #tl_pre
-                let (#input_name, selector) = #parser(#input_name)?;
-                #(#variants_code)*
-                Err(::nom::Err::Error((#orig_input_name, ::nom::error::ErrorKind::Switch)))
+    let to_repr_arms : Vec<_> =
+        variant_values.iter()
+            .map(|(id, value)| quote!{ #name::#id => #value, })
+            .collect();
+    let to_repr_other_arm = match &other_variant {
+        Some(other_ident) => quote!{ #name::#other_ident(x) => *x, },
+        None => quote!{},
+    };
+    let generic_errors = is_generic_errors(&meta_list);
+    let error_kind = get_error_kind(&meta_list);
+    let no_match_case = match &other_variant {
+        Some(other_ident) => quote!{ Ok((#input_name, #name::#other_ident(selector))) },
+        None if generic_errors => quote!{ Err(::nom::Err::Error(NomErr::from_error_kind(#orig_input_name, #error_kind))) },
+        None => quote!{ Err(::nom::Err::Error((#orig_input_name, #error_kind))) },
+    };
+    let to_repr_fn = quote!{
+        /// Returns the wire value corresponding to this variant
+        fn to_repr(&self) -> #selector_ty {
+            match self {
+                #(#to_repr_arms)*
+                #to_repr_other_arm
+            }
+        }
+    };
+    let tokens = if generic_errors {
+        quote!{
+            impl#generics #name#generics {
+                fn parse<NomErr: nom::error::ParseError<&[u8]>>(#orig_input_name: &[u8]) -> nom::IResult<&[u8],#name,NomErr> {
+                    let #input_name = #orig_input_name;
+                    #tl_pre
+                    let (#input_name, selector) = #parser(#input_name)?;
+                    #(#variants_code)*
+                    #no_match_case
+                }
+                #to_repr_fn
+            }
+        }
+    } else {
+        quote!{
+            impl#generics #name#generics {
+                fn parse(#orig_input_name: &[u8]) -> nom::IResult<&[u8],#name> {
+                    let #input_name = #orig_input_name;
+                    #tl_pre
+                    let (#input_name, selector) = #parser(#input_name)?;
+                    #(#variants_code)*
+                    #no_match_case
+                }
+                #to_repr_fn
             }
         }
     };
@@ -165,10 +289,17 @@ pub(crate) fn impl_nom_enums(ast: &syn::DeriveInput, config: &Config) -> TokenSt
     let meta_list = meta::parse_nom_top_level_attribute(&ast.attrs).expect("Parsing the 'nom' meta attribute failed");
     let input_name = syn::Ident::new(&config.input_name, Span::call_site());
     let orig_input_name = syn::Ident::new(&("orig_".to_string() + &config.input_name), Span::call_site());
+    let tag_parser = get_tag_parser(&meta_list);
     let selector = match get_selector(&meta_list) { //.expect("The 'Selector' attribute must be used to give the type of selector item");
-        Some(s) => s,
+        Some(_) if tag_parser.is_some() => {
+            panic!("Nom-derive: 'Selector' and 'Tag' attributes are mutually exclusive (Tag already provides the selector value)");
+        }
+        Some(s) => Some(s),
         None    => {
-            if is_input_fieldless_enum(ast) {
+            if tag_parser.is_some() {
+                // the discriminant is read from the input itself, no 'selector' type is needed
+                None
+            } else if is_input_fieldless_enum(ast) {
                 // check that we have a repr attribute
                 let repr = get_repr(&ast.attrs).expect("Nom-derive: fieldless enums must have a 'repr' attribute");
                 return impl_nom_fieldless_enums(ast, repr, &meta_list, config);
@@ -190,7 +321,7 @@ pub(crate) fn impl_nom_enums(ast: &syn::DeriveInput, config: &Config) -> TokenSt
     // parse string items and prepare tokens for each variant
     let (tl_pre, _tl_post) = get_pre_post_exec(&meta_list, config);
     let generics = &ast.generics;
-    let selector_type : proc_macro2::TokenStream = selector.parse().unwrap();
+    let selector_type : Option<proc_macro2::TokenStream> = selector.as_ref().map(|s| s.parse().unwrap());
     let mut default_case_handled = false;
     let mut variants_code : Vec<_> = {
         variants_defs.iter()
@@ -232,19 +363,50 @@ pub(crate) fn impl_nom_enums(ast: &syn::DeriveInput, config: &Config) -> TokenSt
         }
     }
     // generate code
+    let generic_errors = is_generic_errors(&meta_list);
+    let error_kind = get_error_kind(&meta_list);
     let default_case =
         if default_case_handled { quote!{} }
-        else { quote!{ _ => Err(nom::Err::Error(nom::error_position!(#input_name, nom::error::ErrorKind::Switch))) } };
-    let tokens = quote!{
-        impl#generics #name#generics {
-            fn parse(#orig_input_name: &[u8], selector: #selector_type) -> nom::IResult<&[u8],#name> {
-                let #input_name = #orig_input_name;
-                #tl_pre
-                let enum_def = match selector {
-                    #(#variants_code)*
-                    #default_case
-                };
-                enum_def
+        else if generic_errors { quote!{ _ => Err(nom::Err::Error(NomErr::from_error_kind(#input_name, #error_kind))) } }
+        else { quote!{ _ => Err(nom::Err::Error(nom::error_position!(#input_name, #error_kind))) } };
+    // when a tag parser is given, the discriminant is read from the input itself and there
+    // is no 'selector' parameter; otherwise the caller passes 'selector' in
+    let selector_binding = match &tag_parser {
+        Some(tag_parser) => quote!{ let (#input_name, selector) = #tag_parser(#input_name)?; },
+        None => quote!{},
+    };
+    let fn_params = match &selector_type {
+        Some(selector_type) => quote!{ #orig_input_name: &[u8], selector: #selector_type },
+        None => quote!{ #orig_input_name: &[u8] },
+    };
+    let tokens = if generic_errors {
+        quote!{
+            impl#generics #name#generics {
+                fn parse<NomErr: nom::error::ParseError<&[u8]>>(#fn_params) -> nom::IResult<&[u8],#name,NomErr> {
+                    let #input_name = #orig_input_name;
+                    #tl_pre
+                    #selector_binding
+                    let enum_def = match selector {
+                        #(#variants_code)*
+                        #default_case
+                    };
+                    enum_def
+                }
+            }
+        }
+    } else {
+        quote!{
+            impl#generics #name#generics {
+                fn parse(#fn_params) -> nom::IResult<&[u8],#name> {
+                    let #input_name = #orig_input_name;
+                    #tl_pre
+                    #selector_binding
+                    let enum_def = match selector {
+                        #(#variants_code)*
+                        #default_case
+                    };
+                    enum_def
+                }
             }
         }
     };
@@ -0,0 +1,142 @@
+use nom::error::VerboseError;
+use nom_derive::Nom;
+
+#[derive(Debug, PartialEq, Nom)]
+#[repr(u8)]
+enum FieldlessWithOther {
+    A,
+    B,
+    #[nom(Other)]
+    Unknown(u8),
+}
+
+#[test]
+fn parse_known_variant() {
+    let input = [0x00];
+    let (rem, val) = FieldlessWithOther::parse(&input).unwrap();
+    assert!(rem.is_empty());
+    assert_eq!(val, FieldlessWithOther::A);
+}
+
+#[test]
+fn parse_unmatched_discriminant_falls_back_to_other() {
+    let input = [0xff];
+    let (rem, val) = FieldlessWithOther::parse(&input).unwrap();
+    assert!(rem.is_empty());
+    assert_eq!(val, FieldlessWithOther::Unknown(0xff));
+}
+
+#[derive(Debug, PartialEq, Nom)]
+#[nom(GenericErrors)]
+#[repr(u8)]
+enum GenericErrorEnum {
+    A,
+    B,
+}
+
+#[test]
+fn parse_is_generic_over_the_error_type() {
+    let input = [0x00];
+    let res: nom::IResult<&[u8], GenericErrorEnum, VerboseError<&[u8]>> =
+        GenericErrorEnum::parse(&input);
+    assert_eq!(res.unwrap().1, GenericErrorEnum::A);
+}
+
+#[test]
+fn parse_reports_failure_through_the_caller_chosen_error_type() {
+    let input = [0xff];
+    let res: nom::IResult<&[u8], GenericErrorEnum, VerboseError<&[u8]>> =
+        GenericErrorEnum::parse(&input);
+    assert!(res.is_err());
+}
+
+#[derive(Debug, PartialEq, Nom)]
+#[repr(u16)]
+enum WireCode {
+    A = 0x10,
+    B = 0x20,
+    C,
+}
+
+#[test]
+fn explicit_discriminants_are_honored_and_resync_the_next_implicit_value() {
+    assert_eq!(WireCode::parse(&[0x00, 0x10]).unwrap().1, WireCode::A);
+    assert_eq!(WireCode::parse(&[0x00, 0x20]).unwrap().1, WireCode::B);
+    assert_eq!(WireCode::parse(&[0x00, 0x21]).unwrap().1, WireCode::C);
+}
+
+#[derive(Debug, PartialEq, Nom)]
+#[repr(u24)]
+enum Triplet {
+    X,
+    Y,
+}
+
+#[test]
+fn u24_repr_parses_and_casts_the_selector_as_u32() {
+    assert_eq!(Triplet::parse(&[0x00, 0x00, 0x00]).unwrap().1, Triplet::X);
+    assert_eq!(Triplet::parse(&[0x00, 0x00, 0x01]).unwrap().1, Triplet::Y);
+}
+
+#[derive(Debug, PartialEq, Nom)]
+#[repr(i8)]
+enum SignedCode {
+    Neg = -1,
+    Zero,
+}
+
+#[test]
+fn negative_discriminants_are_parsed_and_resync_the_counter() {
+    assert_eq!(SignedCode::parse(&[0xff]).unwrap().1, SignedCode::Neg);
+    assert_eq!(SignedCode::parse(&[0x00]).unwrap().1, SignedCode::Zero);
+}
+
+#[derive(Debug, PartialEq, Nom)]
+#[repr(u8)]
+enum OtherInTheMiddle {
+    A,
+    #[nom(Other)]
+    Unknown(u8),
+    B,
+}
+
+#[test]
+fn other_variant_still_consumes_its_positional_discriminant_slot() {
+    assert_eq!(OtherInTheMiddle::parse(&[0x00]).unwrap().1, OtherInTheMiddle::A);
+    assert_eq!(OtherInTheMiddle::parse(&[0x02]).unwrap().1, OtherInTheMiddle::B);
+    assert_eq!(OtherInTheMiddle::parse(&[0x01]).unwrap().1, OtherInTheMiddle::Unknown(0x01));
+}
+
+#[derive(Debug, PartialEq, Nom)]
+#[nom(Tag(nom::number::streaming::be_u8))]
+enum TaggedMessage {
+    #[nom(Selector = "0x01")]
+    Ping,
+    #[nom(Selector = "0x02")]
+    Pong(u8),
+}
+
+#[test]
+fn tagged_enum_reads_its_own_discriminant_from_the_input() {
+    let (rem, msg) = TaggedMessage::parse(&[0x01]).unwrap();
+    assert!(rem.is_empty());
+    assert_eq!(msg, TaggedMessage::Ping);
+
+    let (rem, msg) = TaggedMessage::parse(&[0x02, 0x2a]).unwrap();
+    assert!(rem.is_empty());
+    assert_eq!(msg, TaggedMessage::Pong(0x2a));
+}
+
+#[test]
+fn to_repr_round_trips_known_and_other_variants() {
+    assert_eq!(FieldlessWithOther::A.to_repr(), 0x00);
+    assert_eq!(FieldlessWithOther::B.to_repr(), 0x01);
+    assert_eq!(FieldlessWithOther::Unknown(0x42).to_repr(), 0x42);
+}
+
+#[test]
+fn to_repr_honors_explicit_discriminants() {
+    assert_eq!(WireCode::A.to_repr(), 0x10);
+    assert_eq!(WireCode::B.to_repr(), 0x20);
+    assert_eq!(WireCode::C.to_repr(), 0x21);
+}